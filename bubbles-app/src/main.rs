@@ -6,8 +6,9 @@ use relm4::prelude::{AsyncFactoryComponent, AsyncFactoryVecDeque};
 use relm4::{
     AsyncFactorySender, Component, ComponentController, ComponentParts, ComponentSender, Controller, RelmApp, SimpleComponent, spawn
 };
-use std::{env, fs, path::{Path, PathBuf}, ffi::{OsStr, OsString}};
+use std::{env, fs, path::{Path, PathBuf}, ffi::{OsStr, OsString}, collections::HashMap};
 use libc::SIGTERM;
+use serde::{Deserialize, Serialize};
 use tokio::io::{AsyncWriteExt, AsyncReadExt};
 
 fn get_data_dir() -> PathBuf {
@@ -62,6 +63,18 @@ fn wayland_sock_path() -> PathBuf {
     }
 }
 
+fn audio_sock_path() -> PathBuf {
+    // Same host-vs-Flatpak split as `wayland_sock_path` -- PipeWire's
+    // main socket lives at `$XDG_RUNTIME_DIR/pipewire-0`.
+    if is_flatpak() {
+        let uid = unsafe { libc::getuid() };
+        PathBuf::from(format!("/run/user/{}/pipewire-0", uid))
+    } else {
+        let runtime_dir = env::var("XDG_RUNTIME_DIR").expect("XDG_RUNTIME_DIR");
+        PathBuf::from(runtime_dir).join("pipewire-0")
+    }
+}
+
 async fn unix_http(socket: &Path, method: &str, path: &str) -> std::io::Result<String> {
     let mut stream = tokio::net::UnixStream::connect(socket).await?;
     // Content-Length: 0 included for POST correctness; harmless on GET
@@ -76,6 +89,50 @@ async fn unix_http(socket: &Path, method: &str, path: &str) -> std::io::Result<S
 }
 
 struct CreateBubbleDialog {
+    catalog: Vec<ImageCatalogEntry>,
+    // Parallel to `catalog`, kept current via `ImageStatusChanged` so the
+    // dialog can gate creation on the selected image being `Present`.
+    statuses: Vec<ImageStatus>,
+    selected_image_index: u32,
+}
+
+#[derive(Debug)]
+enum CreateBubbleDialogMsg {
+    ImageSelected(u32),
+    Submit(String),
+    ImageStatusChanged(String, ImageStatus),
+}
+
+// A qcow2's disk alone doesn't say which distro it was built from, so
+// importing has to ask -- otherwise `import_vm` has to guess a kernel/initrd
+// and likely guesses wrong.
+struct ImportBubbleDialog {
+    catalog: Vec<ImageCatalogEntry>,
+    selected_image_index: u32,
+    chosen_file: Option<PathBuf>,
+}
+
+#[derive(Debug)]
+enum ImportBubbleDialogMsg {
+    ImageSelected(u32),
+    ChooseFile,
+    FileChosen(PathBuf),
+    Submit,
+}
+
+struct VmSettingsDialog {
+    root_dialog: relm4::adw::PreferencesDialog,
+    vm_name: String,
+    config: VmConfig,
+}
+
+#[derive(Debug)]
+enum VmSettingsDialogMsg {
+    SetTarget(String, VmConfig),
+    MemoryChanged(f64),
+    CpuCoresChanged(f64),
+    ExtraDiskChanged(f64),
+    AudioEnabledChanged(bool),
 }
 
 struct WarnCloseDialog {
@@ -85,20 +142,170 @@ struct WarnCloseDialog {
 #[derive(PartialEq, Debug, Clone)]
 enum ImageStatus {
     NotPresent,
-    Downloading,
+    Downloading { fraction: f64 },
     Present,
+    Failed(String),
+    UpdateAvailable { current: String, latest: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct ImageCatalogEntry {
+    id: String,
+    display_name: String,
+    oras_ref: String,
+    image_dir: String,
+    // Rough, best-effort size used only to turn bytes-on-disk into a
+    // progress fraction -- oras doesn't expose real-time totals.
+    #[serde(default = "default_expected_bytes")]
+    expected_bytes: u64,
+    // e.g. "stable", "beta", "dev" -- purely informational today, shown
+    // next to the display name so a catalog can offer several channels
+    // of the same distribution side by side.
+    #[serde(default = "default_release_channel")]
+    release_channel: String,
+}
+
+fn default_expected_bytes() -> u64 {
+    2_000_000_000
+}
+
+fn default_release_channel() -> String {
+    "stable".to_string()
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct ImageCatalogFile {
+    #[serde(default)]
+    images: Vec<ImageCatalogEntry>,
+}
+
+fn default_image_catalog() -> Vec<ImageCatalogEntry> {
+    vec![ImageCatalogEntry {
+        id: "debian-13".to_string(),
+        display_name: "Debian 13 Bubbles Distribution".to_string(),
+        oras_ref: "ghcr.io/gonicus/bubbles/vm-image:e289a3a5479817c3ffad6bb62d8214e4265e8e4b".to_string(),
+        image_dir: "debian-13".to_string(),
+        expected_bytes: default_expected_bytes(),
+        release_channel: default_release_channel(),
+    }]
+}
+
+fn image_catalog_path() -> PathBuf {
+    get_data_dir().join("catalog.toml")
 }
 
-fn determine_download_status() -> ImageStatus {
+// Bundled defaults, overlaid with a user-editable `catalog.toml` (matched by
+// `id`) so people can point at their own oras refs or add more distributions.
+fn load_image_catalog() -> Vec<ImageCatalogEntry> {
+    let mut catalog = default_image_catalog();
+    let user_entries = fs::read_to_string(image_catalog_path())
+        .ok()
+        .and_then(|contents| toml::from_str::<ImageCatalogFile>(&contents).ok())
+        .map(|file| file.images)
+        .unwrap_or_default();
+    for entry in user_entries {
+        match catalog.iter_mut().find(|existing| existing.id == entry.id) {
+            Some(existing) => *existing = entry,
+            None => catalog.push(entry),
+        }
+    }
+    catalog
+}
+
+// Marker file dropped next to a downloaded image recording the `oras_ref`
+// it was pulled from, so a later catalog refresh (a new ref for the same
+// `id`) can be noticed without talking to the registry again.
+fn image_source_marker_path(entry: &ImageCatalogEntry) -> PathBuf {
+    get_data_dir().join("images").join(&entry.image_dir).join(".source")
+}
+
+fn determine_download_status(entry: &ImageCatalogEntry) -> ImageStatus {
     let images_dir = get_data_dir().join("images");
     fs::create_dir_all(&images_dir).expect("directory to exist or be created");
 
-    let image_exists = images_dir.join(Path::new("debian-13")).exists();
+    let image_exists = images_dir.join(Path::new(&entry.image_dir)).exists();
+    if !image_exists {
+        return ImageStatus::NotPresent;
+    }
+
+    match fs::read_to_string(image_source_marker_path(entry)) {
+        Ok(installed_ref) if installed_ref != entry.oras_ref => ImageStatus::UpdateAvailable {
+            current: installed_ref,
+            latest: entry.oras_ref.clone(),
+        },
+        // No marker (image predates this check) or marker matches -- either
+        // way, nothing newer is known locally, so treat it as up to date.
+        // `check_remote_update` is the actual registry-side version check.
+        _ => ImageStatus::Present,
+    }
+}
+
+// Marker file recording the manifest digest `oras_ref` resolved to the last
+// time this image was pulled, so a later `check_remote_update` has something
+// to diff the registry's current digest against.
+fn image_digest_marker_path(entry: &ImageCatalogEntry) -> PathBuf {
+    get_data_dir().join("images").join(&entry.image_dir).join(".digest")
+}
 
-    return match image_exists {
-        true => ImageStatus::Present,
-        false => ImageStatus::NotPresent,
+// `oras resolve` is a manifest-only round trip (no blob pull), so it's cheap
+// enough to run in the background without a blocking spinner.
+async fn fetch_remote_digest(oras_ref: &str) -> Option<String> {
+    let oras_bin = if is_flatpak() { "/app/bin/oras" } else { "oras" };
+    let process = gtk::gio::Subprocess::newv(&[
+        OsStr::new(oras_bin),
+        OsStr::new("resolve"),
+        OsStr::new(oras_ref),
+    ], SubprocessFlags::STDOUT_PIPE | SubprocessFlags::STDERR_SILENCE).ok()?;
+    let (stdout, _) = process.communicate_utf8_future(None).await.ok()?;
+    let digest = stdout?.trim().to_string();
+    (process.is_successful() && !digest.is_empty()).then_some(digest)
+}
+
+// The actual "is there a newer image upstream" check: compares the
+// registry's current manifest digest for `entry.oras_ref` against the one
+// recorded the last time this image was pulled. Unlike
+// `determine_download_status` (a same-process, no-network comparison against
+// the in-memory catalog) this talks to the registry, so it also catches the
+// case where `oras_ref` didn't change but what it points at did.
+async fn check_remote_update(entry: &ImageCatalogEntry) -> Option<ImageStatus> {
+    if !get_data_dir().join("images").join(&entry.image_dir).exists() {
+        return None;
+    }
+    let installed_digest = fs::read_to_string(image_digest_marker_path(entry)).ok()?;
+    let latest_digest = fetch_remote_digest(&entry.oras_ref).await?;
+    (installed_digest != latest_digest).then_some(ImageStatus::UpdateAvailable {
+        current: installed_digest,
+        latest: latest_digest,
+    })
+}
+
+// The folder chooser is portal-backed under Flatpak, so the `PathBuf` it
+// hands back can point into the sandbox's private document-portal mount
+// rather than the literal path crosvm -- launched host-side via
+// `flatpak-spawn --host`, see `make_host_args` -- needs for `--shared-dir`.
+// Resolve it the same way `wait_until_exists` checks existence: ask the host.
+async fn resolve_host_path(path: &Path) -> PathBuf {
+    if !is_flatpak() {
+        return path.to_path_buf();
+    }
+    let args = make_host_args(&[
+        OsStr::new("readlink"),
+        OsStr::new("-f"),
+        path.as_os_str(),
+    ]);
+    let args_ref: Vec<&OsStr> = args.iter().map(OsString::as_os_str).collect();
+    let Ok(process) = gtk::gio::Subprocess::newv(
+        &args_ref,
+        SubprocessFlags::STDOUT_PIPE | SubprocessFlags::STDERR_SILENCE,
+    ) else {
+        return path.to_path_buf();
     };
+    match process.communicate_utf8_future(None).await {
+        Ok((Some(stdout), _)) if process.is_successful() && !stdout.trim().is_empty() => {
+            PathBuf::from(stdout.trim())
+        }
+        _ => path.to_path_buf(),
+    }
 }
 
 pub async fn wait_until_exists(path: &Path) {
@@ -142,45 +349,138 @@ pub async fn request_terminal(vsock_socket_path: &Path) {
     unix_http(vsock_socket_path, "POST", "/spawn-terminal").await.ok();
 }
 
-async fn download_image() {
-    let target_dir = get_data_dir().join("images/debian-13");
-    tokio::fs::create_dir_all(&target_dir).await.unwrap();
+// crosvm's control socket (created via `--socket` and already passed at
+// launch) accepts these as `crosvm <cmd> <socket>` invocations.
+async fn crosvm_control(crosvm_socket_path: &Path, args: &[&str]) {
+    let crosvm_bin: OsString = if is_flatpak() {
+        flatpak_host_bin("crosvm").into_os_string()
+    } else {
+        OsString::from("crosvm")
+    };
+    let mut full_args: Vec<&OsStr> = vec![crosvm_bin.as_os_str()];
+    full_args.extend(args.iter().map(|a| OsStr::new(*a)));
+    full_args.push(crosvm_socket_path.as_os_str());
+    let host_args = make_host_args(&full_args);
+    let host_args_ref: Vec<&OsStr> = host_args.iter().map(OsString::as_os_str).collect();
+    gtk::gio::Subprocess::newv(&host_args_ref, SubprocessFlags::empty())
+        .expect("start of crosvm control process")
+        .wait_future().await
+        .expect("crosvm control command to complete");
+}
 
-    // Step 1: oras pull (runs inside sandbox — just needs --share=network)
-    // In Flatpak: bundled at /app/bin/oras; outside: resolved via PATH
-    let oras_bin = if is_flatpak() { "/app/bin/oras" } else { "oras" };
-    gtk::gio::Subprocess::newv(&[
-        OsStr::new(oras_bin),
-        OsStr::new("pull"),
-        OsStr::new("ghcr.io/gonicus/bubbles/vm-image:e289a3a5479817c3ffad6bb62d8214e4265e8e4b"),
-        OsStr::new("--output"),
-        target_dir.as_os_str(),
-    ], SubprocessFlags::empty())
-        .expect("oras pull to start")
-        .wait_future().await.expect("oras pull to complete");
-
-    // Step 2: qemu-img convert
-    // In Flatpak: bundled at /app/bin/qemu-img; outside: resolved via PATH
-    let qemu_img = if is_flatpak() { "/app/bin/qemu-img" } else { "qemu-img" };
-    let qcow2_path = target_dir.join("disk.qcow2");
-    let raw_path = target_dir.join("disk.img");
-    gtk::gio::Subprocess::newv(&[
-        OsStr::new(qemu_img),
-        OsStr::new("convert"),
-        OsStr::new("-f"), OsStr::new("qcow2"),
-        OsStr::new("-O"), OsStr::new("raw"),
-        qcow2_path.as_os_str(),
-        raw_path.as_os_str(),
-    ], SubprocessFlags::empty())
-        .expect("qemu-img to start")
-        .wait_future().await.expect("qemu-img to complete");
+pub async fn crosvm_suspend(crosvm_socket_path: &Path) {
+    crosvm_control(crosvm_socket_path, &["suspend"]).await;
+}
 
-    tokio::fs::remove_file(&qcow2_path).await.ok();
+pub async fn crosvm_resume(crosvm_socket_path: &Path) {
+    crosvm_control(crosvm_socket_path, &["resume"]).await;
+}
+
+pub async fn crosvm_balloon(crosvm_socket_path: &Path, target_mib: u64) {
+    crosvm_control(crosvm_socket_path, &["balloon", &target_mib.to_string()]).await;
+}
+
+pub async fn crosvm_snapshot_take(crosvm_socket_path: &Path, snapshot_path: &Path) {
+    crosvm_control(
+        crosvm_socket_path,
+        &["snapshot", "take", snapshot_path.to_str().expect("string")],
+    ).await;
+}
+
+pub async fn crosvm_snapshot_restore(crosvm_socket_path: &Path, snapshot_path: &Path) {
+    crosvm_control(
+        crosvm_socket_path,
+        &["snapshot", "restore", snapshot_path.to_str().expect("string")],
+    ).await;
+}
 
-    // Step 3: expand disk (native Rust, no truncate binary needed)
-    let f = tokio::fs::OpenOptions::new().write(true).open(&raw_path).await.unwrap();
-    let current_size = f.metadata().await.unwrap().len();
-    f.set_len(current_size + 15 * 1024 * 1024 * 1024).await.unwrap();
+// In Flatpak: bundled at /app/bin/qemu-img; outside: resolved via PATH
+fn qemu_img_bin() -> &'static str {
+    if is_flatpak() { "/app/bin/qemu-img" } else { "qemu-img" }
+}
+
+fn dir_size(path: &Path) -> u64 {
+    let mut total = 0u64;
+    if let Ok(entries) = fs::read_dir(path) {
+        for entry in entries.flatten() {
+            if let Ok(metadata) = entry.metadata() {
+                if metadata.is_dir() {
+                    total += dir_size(&entry.path());
+                } else {
+                    total += metadata.len();
+                }
+            }
+        }
+    }
+    total
+}
+
+// Reports (bytes_downloaded, total_bytes) on `progress` as the pull/convert
+// proceed, by polling the on-disk size against `entry.expected_bytes` --
+// oras doesn't expose a machine-readable progress stream of its own.
+async fn download_image(
+    entry: &ImageCatalogEntry,
+    progress: tokio::sync::mpsc::UnboundedSender<(u64, u64)>,
+) -> Result<(), String> {
+    let target_dir = get_data_dir().join("images").join(&entry.image_dir);
+    tokio::fs::create_dir_all(&target_dir).await.map_err(|e| e.to_string())?;
+
+    let total_bytes = entry.expected_bytes;
+    let done = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+    let poll_done = done.clone();
+    let poll_dir = target_dir.clone();
+    let poll_progress = progress.clone();
+    relm4::spawn_local(async move {
+        while !poll_done.load(std::sync::atomic::Ordering::Relaxed) {
+            let bytes = dir_size(&poll_dir).min(total_bytes);
+            poll_progress.send((bytes, total_bytes)).ok();
+            tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+        }
+    });
+
+    let result: Result<(), String> = async {
+        // Step 1: oras pull (runs inside sandbox — just needs --share=network)
+        // In Flatpak: bundled at /app/bin/oras; outside: resolved via PATH
+        let oras_bin = if is_flatpak() { "/app/bin/oras" } else { "oras" };
+        gtk::gio::Subprocess::newv(&[
+            OsStr::new(oras_bin),
+            OsStr::new("pull"),
+            OsStr::new(&entry.oras_ref),
+            OsStr::new("--output"),
+            target_dir.as_os_str(),
+        ], SubprocessFlags::empty())
+            .map_err(|e| e.to_string())?
+            .wait_check_future().await.map_err(|e| format!("oras pull failed: {}", e))?;
+
+        // Step 2: qemu-img convert
+        let qemu_img = qemu_img_bin();
+        let qcow2_path = target_dir.join("disk.qcow2");
+        let raw_path = target_dir.join("disk.img");
+        gtk::gio::Subprocess::newv(&[
+            OsStr::new(qemu_img),
+            OsStr::new("convert"),
+            OsStr::new("-f"), OsStr::new("qcow2"),
+            OsStr::new("-O"), OsStr::new("raw"),
+            qcow2_path.as_os_str(),
+            raw_path.as_os_str(),
+        ], SubprocessFlags::empty())
+            .map_err(|e| e.to_string())?
+            .wait_check_future().await.map_err(|e| format!("qemu-img convert failed: {}", e))?;
+
+        tokio::fs::remove_file(&qcow2_path).await.ok();
+        // Disk expansion now happens per-bubble in `create_vm`, sized from
+        // that bubble's `VmConfig::extra_disk_gib`, so different bubbles
+        // can get different amounts of extra space from the same base image.
+        tokio::fs::write(image_source_marker_path(entry), &entry.oras_ref).await.ok();
+        if let Some(digest) = fetch_remote_digest(&entry.oras_ref).await {
+            tokio::fs::write(image_digest_marker_path(entry), &digest).await.ok();
+        }
+        Ok(())
+    }.await;
+
+    done.store(true, std::sync::atomic::Ordering::Relaxed);
+    progress.send((total_bytes, total_bytes)).ok();
+    result
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -232,8 +532,8 @@ impl SimpleComponent for WarnCloseDialog {
 
 #[relm4::component]
 impl SimpleComponent for CreateBubbleDialog {
-    type Init = ();
-    type Input = ();
+    type Init = Vec<ImageCatalogEntry>;
+    type Input = CreateBubbleDialogMsg;
     type Output = AppMsg;
 
     view! {
@@ -243,15 +543,231 @@ impl SimpleComponent for CreateBubbleDialog {
             set_child = &relm4::adw::StatusPage {
                 set_icon_name: Some("window-new-symbolic"),
                 set_title: "Create new Bubble",
-                set_description: Some("Enter name and confirm with ENTER"),
+                set_description: Some("Choose a base image, enter a name, and confirm with ENTER"),
                 #[wrap(Some)]
-                set_child = &gtk::Entry {
-                    connect_activate[sender] => move |entry| {
-                        let name: String = entry.text().into();
-                        sender.output(AppMsg::CreateNewBubble(name)).unwrap();
-                        entry.buffer().delete_text(0, None);
+                set_child = &gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_spacing: 8,
+                    append = &gtk::DropDown {
+                        set_model: Some(&gtk::StringList::new(
+                            &model.catalog.iter().map(|entry| entry.display_name.as_str()).collect::<Vec<_>>()
+                        )),
+                        connect_selected_notify[sender] => move |dropdown| {
+                            sender.input(CreateBubbleDialogMsg::ImageSelected(dropdown.selected()));
+                        },
+                    },
+                    append = &gtk::Label {
+                        #[watch]
+                        set_label: &match self.statuses.get(self.selected_image_index as usize) {
+                            Some(ImageStatus::Present) => "Image ready".to_string(),
+                            Some(ImageStatus::UpdateAvailable { .. }) => "Image ready (update available)".to_string(),
+                            Some(ImageStatus::Downloading { fraction }) => format!("Downloading selected image… {}%", (fraction * 100.0).round() as i64),
+                            Some(ImageStatus::Failed(reason)) => format!("Image download failed: {reason}"),
+                            Some(ImageStatus::NotPresent) | None => "Image not downloaded yet — press ENTER to fetch it".to_string(),
+                        },
+                        set_halign: gtk::Align::Start,
+                    },
+                    append = &gtk::Entry {
+                        #[watch]
+                        set_sensitive: !matches!(self.statuses.get(self.selected_image_index as usize), Some(ImageStatus::Downloading { .. })),
+                        connect_activate[sender] => move |entry| {
+                            let name: String = entry.text().into();
+                            sender.input(CreateBubbleDialogMsg::Submit(name));
+                            entry.buffer().delete_text(0, None);
+                        }
+                    },
+                },
+            },
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let statuses = init.iter().map(determine_download_status).collect();
+        let model = CreateBubbleDialog { catalog: init, statuses, selected_image_index: 0 };
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>) {
+        match msg {
+            CreateBubbleDialogMsg::ImageSelected(index) => {
+                self.selected_image_index = index;
+            }
+            CreateBubbleDialogMsg::ImageStatusChanged(id, status) => {
+                if let Some(position) = self.catalog.iter().position(|entry| entry.id == id) {
+                    self.statuses[position] = status;
+                }
+            }
+            CreateBubbleDialogMsg::Submit(name) => {
+                let Some(image) = self.catalog.get(self.selected_image_index as usize) else {
+                    return;
+                };
+                let image_id = image.id.clone();
+                match self.statuses.get(self.selected_image_index as usize) {
+                    Some(ImageStatus::Present) | Some(ImageStatus::UpdateAvailable { .. }) => {
+                        sender.output(AppMsg::CreateNewBubble { name, image_id }).unwrap();
                         sender.output(AppMsg::HideBubbleCreationDialog).unwrap();
                     }
+                    _ => {
+                        // Not downloaded (or previously failed) -- fetch it on
+                        // demand instead of creating from a missing image.
+                        sender.output(AppMsg::DownloadCatalogImage(image_id)).unwrap();
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[relm4::component]
+impl SimpleComponent for ImportBubbleDialog {
+    type Init = Vec<ImageCatalogEntry>;
+    type Input = ImportBubbleDialogMsg;
+    type Output = AppMsg;
+
+    view! {
+        dialog = relm4::adw::Dialog {
+            set_presentation_mode: relm4::adw::DialogPresentationMode::BottomSheet,
+            #[wrap(Some)]
+            set_child = &relm4::adw::StatusPage {
+                set_icon_name: Some("document-open-symbolic"),
+                set_title: "Import Bubble",
+                set_description: Some("Choose the base image the disk was created from, then pick its qcow2 file"),
+                #[wrap(Some)]
+                set_child = &gtk::Box {
+                    set_orientation: gtk::Orientation::Vertical,
+                    set_spacing: 8,
+                    append = &gtk::DropDown {
+                        set_model: Some(&gtk::StringList::new(
+                            &model.catalog.iter().map(|entry| entry.display_name.as_str()).collect::<Vec<_>>()
+                        )),
+                        connect_selected_notify[sender] => move |dropdown| {
+                            sender.input(ImportBubbleDialogMsg::ImageSelected(dropdown.selected()));
+                        },
+                    },
+                    append = &gtk::Button {
+                        set_label: "Choose file…",
+                        connect_clicked[sender] => move |_| {
+                            sender.input(ImportBubbleDialogMsg::ChooseFile);
+                        }
+                    },
+                    append = &gtk::Label {
+                        #[watch]
+                        set_label: &match &self.chosen_file {
+                            Some(path) => format!("File: {}", path.display()),
+                            None => "No file chosen yet".to_string(),
+                        },
+                        set_halign: gtk::Align::Start,
+                    },
+                    append = &gtk::Button {
+                        set_label: "Import",
+                        set_css_classes: &["suggested-action"],
+                        #[watch]
+                        set_sensitive: self.chosen_file.is_some(),
+                        connect_clicked[sender] => move |_| {
+                            sender.input(ImportBubbleDialogMsg::Submit);
+                        }
+                    },
+                },
+            },
+        }
+    }
+
+    fn init(
+        init: Self::Init,
+        root: Self::Root,
+        sender: ComponentSender<Self>,
+    ) -> ComponentParts<Self> {
+        let model = ImportBubbleDialog { catalog: init, selected_image_index: 0, chosen_file: None };
+        let widgets = view_output!();
+        ComponentParts { model, widgets }
+    }
+
+    fn update(&mut self, msg: Self::Input, sender: ComponentSender<Self>) {
+        match msg {
+            ImportBubbleDialogMsg::ImageSelected(index) => {
+                self.selected_image_index = index;
+            }
+            ImportBubbleDialogMsg::ChooseFile => {
+                let sender = sender.clone();
+                relm4::spawn_local(async move {
+                    let dialog = gtk::FileDialog::new();
+                    if let Ok(file) = dialog.open_future(None::<&gtk::Window>).await {
+                        if let Some(path) = file.path() {
+                            sender.input(ImportBubbleDialogMsg::FileChosen(path));
+                        }
+                    }
+                });
+            }
+            ImportBubbleDialogMsg::FileChosen(path) => {
+                self.chosen_file = Some(path);
+            }
+            ImportBubbleDialogMsg::Submit => {
+                let Some(src) = self.chosen_file.take() else {
+                    return;
+                };
+                let Some(image) = self.catalog.get(self.selected_image_index as usize) else {
+                    return;
+                };
+                sender.output(AppMsg::ImportBubble(src, image.id.clone())).unwrap();
+                sender.output(AppMsg::HideImportBubbleDialog).unwrap();
+            }
+        }
+    }
+}
+
+#[relm4::component]
+impl SimpleComponent for VmSettingsDialog {
+    type Init = ();
+    type Input = VmSettingsDialogMsg;
+    type Output = ();
+
+    view! {
+        dialog = relm4::adw::PreferencesDialog {
+            set_title: "Bubble settings",
+            add = &relm4::adw::PreferencesPage {
+                add = &relm4::adw::PreferencesGroup {
+                    set_title: "Resources",
+                    add = &relm4::adw::SpinRow::with_range(256.0, 64000.0, 256.0) {
+                        set_title: "Memory (MiB)",
+                        #[watch]
+                        set_value: self.config.memory_mib as f64,
+                        connect_value_notify[sender] => move |row| {
+                            sender.input(VmSettingsDialogMsg::MemoryChanged(row.value()));
+                        },
+                    },
+                    add = &relm4::adw::SpinRow::with_range(1.0, 32.0, 1.0) {
+                        set_title: "CPU cores",
+                        #[watch]
+                        set_value: self.config.cpu_cores as f64,
+                        connect_value_notify[sender] => move |row| {
+                            sender.input(VmSettingsDialogMsg::CpuCoresChanged(row.value()));
+                        },
+                    },
+                    add = &relm4::adw::SpinRow::with_range(0.0, 512.0, 5.0) {
+                        set_title: "Extra disk space (GiB)",
+                        #[watch]
+                        set_value: self.config.extra_disk_gib as f64,
+                        connect_value_notify[sender] => move |row| {
+                            sender.input(VmSettingsDialogMsg::ExtraDiskChanged(row.value()));
+                        },
+                    },
+                },
+                add = &relm4::adw::PreferencesGroup {
+                    set_title: "Devices",
+                    add = &relm4::adw::SwitchRow {
+                        set_title: "Audio passthrough",
+                        set_subtitle: "Forward the host's audio sink into the bubble",
+                        #[watch]
+                        set_active: self.config.audio_enabled,
+                        connect_active_notify[sender] => move |row| {
+                            sender.input(VmSettingsDialogMsg::AudioEnabledChanged(row.is_active()));
+                        },
+                    },
                 },
             },
         }
@@ -260,30 +776,233 @@ impl SimpleComponent for CreateBubbleDialog {
     fn init(
         _init: Self::Init,
         root: Self::Root,
-        sender: ComponentSender<Self>,
+        _sender: ComponentSender<Self>,
     ) -> ComponentParts<Self> {
-        let model = CreateBubbleDialog { };
+        let model = VmSettingsDialog { root_dialog: root.clone(), vm_name: String::new(), config: VmConfig::default() };
         let widgets = view_output!();
         ComponentParts { model, widgets }
     }
 
-    fn update(&mut self, _msg: Self::Input, _sender: ComponentSender<Self>) {}
+    fn update(&mut self, msg: Self::Input, _sender: ComponentSender<Self>) {
+        match msg {
+            VmSettingsDialogMsg::SetTarget(name, config) => {
+                self.vm_name = name;
+                self.config = config;
+            }
+            VmSettingsDialogMsg::MemoryChanged(value) => {
+                self.config.memory_mib = value as u64;
+                self.persist();
+            }
+            VmSettingsDialogMsg::CpuCoresChanged(value) => {
+                self.config.cpu_cores = value as u32;
+                self.persist();
+            }
+            VmSettingsDialogMsg::ExtraDiskChanged(value) => {
+                self.config.extra_disk_gib = value as u64;
+                self.persist();
+            }
+            VmSettingsDialogMsg::AudioEnabledChanged(enabled) => {
+                self.config.audio_enabled = enabled;
+                self.persist();
+            }
+        }
+    }
+}
+
+impl VmSettingsDialog {
+    fn persist(&self) {
+        let vm_name = self.vm_name.clone();
+        let config = self.config.clone();
+        spawn(async move {
+            save_vm_config(&vm_name, &config).await.ok();
+        });
+    }
+}
+
+#[derive(PartialEq, Debug)]
+struct ImageEntry {
+    entry: ImageCatalogEntry,
+    status: ImageStatus,
+}
+
+#[derive(Debug)]
+enum ImageEntryMsg {
+    Download(DynamicIndex),
+    Progress(f64),
+}
+
+#[derive(Debug)]
+enum ImageEntryUpdate {
+    StatusChanged(DynamicIndex, ImageStatus),
+}
+
+#[relm4::factory(async)]
+impl AsyncFactoryComponent for ImageEntry {
+    type Init = ImageCatalogEntry;
+    type Input = ImageEntryMsg;
+    type Output = ImageEntryUpdate;
+    type CommandOutput = ();
+    type ParentWidget = gtk::ListBox;
+
+    view! {
+        #[root]
+        relm4::adw::ActionRow {
+            set_title: &self.entry.display_name,
+            set_subtitle: &self.entry.release_channel,
+            add_prefix = &gtk::Image {
+                set_icon_name: Some("drive-harddisk-system-symbolic")
+            },
+            add_suffix = &gtk::Box {
+                set_orientation: gtk::Orientation::Horizontal,
+                set_spacing: 5,
+                append = &gtk::ProgressBar {
+                    #[watch]
+                    set_visible: matches!(self.status, ImageStatus::Downloading { .. }),
+                    #[watch]
+                    set_fraction: match self.status {
+                        ImageStatus::Downloading { fraction } => fraction,
+                        _ => 0.0,
+                    },
+                    set_valign: gtk::Align::Center,
+                },
+                append = &gtk::Label {
+                    #[watch]
+                    set_label: &match &self.status {
+                        ImageStatus::Present => "Ready".to_string(),
+                        ImageStatus::NotPresent => "Not downloaded".to_string(),
+                        ImageStatus::Downloading { fraction } => format!("Downloading… {}%", (fraction * 100.0).round() as i64),
+                        ImageStatus::Failed(reason) => format!("Failed: {reason}"),
+                        ImageStatus::UpdateAvailable { .. } => "Update available".to_string(),
+                    }
+                },
+                append = &gtk::Button {
+                    #[watch]
+                    set_sensitive: !matches!(self.status, ImageStatus::Downloading { .. }),
+                    #[watch]
+                    set_icon_name: match self.status {
+                        ImageStatus::Present => "view-refresh-symbolic",
+                        ImageStatus::NotPresent => "folder-download-symbolic",
+                        ImageStatus::Downloading { .. } => "image-loading-symbolic",
+                        ImageStatus::Failed(_) => "view-refresh-symbolic",
+                        ImageStatus::UpdateAvailable { .. } => "software-update-available-symbolic",
+                    },
+                    #[watch]
+                    set_tooltip_text: match self.status {
+                        ImageStatus::Failed(_) => Some("Retry download"),
+                        ImageStatus::UpdateAvailable { .. } => Some("Download the newer image"),
+                        _ => None,
+                    },
+                    connect_clicked[sender, index] => move |_| {
+                        sender.input(ImageEntryMsg::Download(index.clone()));
+                    }
+                }
+            }
+        }
+    }
+
+    async fn init_model(
+        entry: Self::Init,
+        _index: &DynamicIndex,
+        _sender: AsyncFactorySender<Self>,
+    ) -> Self {
+        let status = determine_download_status(&entry);
+        Self { entry, status }
+    }
+
+    async fn update(&mut self, msg: Self::Input, sender: AsyncFactorySender<Self>) {
+        match msg {
+            ImageEntryMsg::Download(index) => {
+                self.status = ImageStatus::Downloading { fraction: 0.0 };
+                sender.output(ImageEntryUpdate::StatusChanged(index.clone(), self.status.clone())).ok();
+
+                let entry = self.entry.clone();
+                let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+                relm4::spawn_local({
+                    let sender = sender.clone();
+                    async move {
+                        while let Some((downloaded, total)) = rx.recv().await {
+                            let fraction = if total == 0 { 0.0 } else { (downloaded as f64 / total as f64).min(0.99) };
+                            sender.input(ImageEntryMsg::Progress(fraction));
+                        }
+                    }
+                });
+
+                relm4::spawn_local(async move {
+                    let status = match download_image(&entry, tx).await {
+                        Ok(()) => determine_download_status(&entry),
+                        Err(reason) => ImageStatus::Failed(reason),
+                    };
+                    sender.output(ImageEntryUpdate::StatusChanged(index, status)).ok();
+                });
+            }
+            ImageEntryMsg::Progress(fraction) => {
+                self.status = ImageStatus::Downloading { fraction };
+            }
+        }
+    }
+}
+
+// A stable handle for an in-flight background operation; only used as a
+// HashMap key so concurrent jobs of the same kind can be told apart.
+type JobId = u64;
+
+#[derive(Debug, Clone, PartialEq)]
+enum JobKind {
+    CreateBubble { name: String },
+    DownloadImage { image_id: String },
+    ExportDisk { vm: String },
 }
 
 struct App {
     vms: AsyncFactoryVecDeque<VmEntry>,
+    images: AsyncFactoryVecDeque<ImageEntry>,
     create_bubble_dialog: Controller<CreateBubbleDialog>,
+    import_bubble_dialog: Controller<ImportBubbleDialog>,
     warn_close_dialog: Controller<WarnCloseDialog>,
-    currently_creating_bubble: bool,
-    image_status: ImageStatus,
+    vm_settings_dialog: Controller<VmSettingsDialog>,
+    jobs: HashMap<JobId, JobKind>,
+    next_job_id: JobId,
+    any_image_present: bool,
+    any_image_downloading: bool,
+    // (image_id, display_name) of the most recent registry-side update found
+    // by `check_remote_update`, surfaced as a non-blocking banner rather than
+    // interrupting whatever the user's doing. The banner has no close action;
+    // it goes away only when its button starts the re-download.
+    update_banner: Option<(String, String)>,
     root: relm4::adw::Window,
 }
 
+impl App {
+    fn is_job_running(&self, kind: &JobKind) -> bool {
+        self.jobs.values().any(|running| running == kind)
+    }
+
+    fn start_job(&mut self, kind: JobKind) -> JobId {
+        let id = self.next_job_id;
+        self.next_job_id += 1;
+        self.jobs.insert(id, kind);
+        id
+    }
+}
+
+// Runs `check_remote_update` in the background and reports back only if it
+// actually found something newer -- called at startup and again after a
+// download finishes (the image may have been stale since before this run).
+fn spawn_remote_update_check(sender: &ComponentSender<App>, entry: ImageCatalogEntry) {
+    let sender = sender.clone();
+    relm4::spawn_local(async move {
+        if let Some(status) = check_remote_update(&entry).await {
+            sender.input(AppMsg::RemoteUpdateFound(entry.id.clone(), status));
+        }
+    });
+}
+
 #[derive(PartialEq, Debug, Clone)]
 enum VMStatus {
     NotRunning,
     Running,
     InFlux,
+    Suspended,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -310,34 +1029,232 @@ fn load_vms() -> Vec<VM> {
     return vms;
 }
 
-async fn create_vm(name: String) {
+// Default crosvm resource grant, used whenever a VM has no config.toml yet
+// (or is missing one of these fields).
+const DEFAULT_MEMORY_MIB: u64 = 7000;
+const DEFAULT_CPU_CORES: u32 = 4;
+const DEFAULT_EXTRA_DISK_GIB: u64 = 15;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct VmConfig {
+    memory_mib: u64,
+    cpu_cores: u32,
+    extra_disk_gib: u64,
+    shared_folders: Vec<SharedFolder>,
+    audio_enabled: bool,
+    image_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+struct SharedFolder {
+    host_path: PathBuf,
+    tag: String,
+}
+
+impl SharedFolder {
+    fn guest_path(&self) -> String {
+        format!("/mnt/shared/{}", self.tag)
+    }
+}
+
+// virtiofs tags are plain strings, but keep them short and shell-safe by
+// deriving them from the folder name instead of trusting the raw path.
+fn sanitize_tag(path: &Path) -> String {
+    let base = path
+        .file_name()
+        .and_then(OsStr::to_str)
+        .unwrap_or("share");
+    let sanitized: String = base
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    sanitized.chars().take(30).collect()
+}
+
+impl Default for VmConfig {
+    fn default() -> Self {
+        Self {
+            memory_mib: DEFAULT_MEMORY_MIB,
+            cpu_cores: DEFAULT_CPU_CORES,
+            extra_disk_gib: DEFAULT_EXTRA_DISK_GIB,
+            shared_folders: Vec::new(),
+            audio_enabled: false,
+            image_id: default_image_catalog()[0].id.clone(),
+        }
+    }
+}
+
+fn vm_config_path(name: &str) -> PathBuf {
+    get_data_dir().join("vms").join(name).join("config.toml")
+}
+
+fn load_vm_config(name: &str) -> VmConfig {
+    fs::read_to_string(vm_config_path(name))
+        .ok()
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default()
+}
+
+async fn save_vm_config(name: &str, config: &VmConfig) -> std::io::Result<()> {
+    let contents = toml::to_string_pretty(config).expect("config to serialize");
+    tokio::fs::write(vm_config_path(name), contents).await
+}
+
+// Grows `disk_path` to `base_size + extra_gib`, if it isn't already that big.
+// Idempotent (unlike a plain `set_len(current + extra)`), so it's safe to call
+// on every start and pick up an `extra_disk_gib` the user raised after creation.
+async fn ensure_disk_capacity(disk_path: &Path, base_size: u64, extra_gib: u64) {
+    let target_size = base_size + extra_gib * 1024 * 1024 * 1024;
+    let f = tokio::fs::OpenOptions::new().write(true).open(disk_path).await.unwrap();
+    if f.metadata().await.unwrap().len() < target_size {
+        f.set_len(target_size).await.unwrap();
+    }
+}
+
+async fn create_vm(name: String, image_id: String) {
     println!("starting copy");
     let vm_dir_path = get_data_dir().join("vms").join(&name);
     tokio::fs::create_dir_all(&vm_dir_path).await.expect("directories to be created");
-    let image_base_path = get_data_dir().join("images/debian-13");
+    let catalog = load_image_catalog();
+    let image = catalog.iter().find(|entry| entry.id == image_id).expect("chosen image to be in the catalog");
+    let image_base_path = get_data_dir().join("images").join(&image.image_dir);
     let image_disk_path = image_base_path.join("disk.img");
     let image_linuz_path = image_base_path.join("vmlinuz");
     let image_initrd_path = image_base_path.join("initrd.img");
-    tokio::fs::copy(image_disk_path, vm_dir_path.join("disk.img")).await.expect("disk copy to succeed");
+    let base_size = tokio::fs::copy(image_disk_path, vm_dir_path.join("disk.img")).await.expect("disk copy to succeed");
     tokio::fs::copy(image_linuz_path, vm_dir_path.join("vmlinuz")).await.expect("vmlinuz copy to succeed");
     tokio::fs::copy(image_initrd_path, vm_dir_path.join("initrd.img")).await.expect("initrd copy to succeed");
+
+    let mut config = VmConfig::default();
+    config.image_id = image_id;
+    ensure_disk_capacity(&vm_dir_path.join("disk.img"), base_size, config.extra_disk_gib).await;
+    save_vm_config(&name, &config).await.expect("config to be written");
     println!("done copy");
 }
 
+// Inverse of the `qemu-img convert` done in `download_image`: hands the user
+// a portable qcow2 they can re-`import_vm` later or open elsewhere.
+async fn export_disk(vm_name: &str, dest: &Path) {
+    let raw_path = get_data_dir().join("vms").join(vm_name).join("disk.img");
+    gtk::gio::Subprocess::newv(&[
+        OsStr::new(qemu_img_bin()),
+        OsStr::new("convert"),
+        OsStr::new("-f"), OsStr::new("raw"),
+        OsStr::new("-O"), OsStr::new("qcow2"),
+        raw_path.as_os_str(),
+        dest.as_os_str(),
+    ], SubprocessFlags::empty())
+        .expect("qemu-img to start")
+        .wait_future().await.expect("qemu-img to complete");
+}
+
+fn disk_snapshots_dir(vm_name: &str) -> PathBuf {
+    get_data_dir().join("vms").join(vm_name).join("snapshots")
+}
+
+// A named point-in-time copy of the bubble's disk, distinct from
+// `crosvm_snapshot_take`'s live memory/device-state snapshot: this one
+// only touches the backing disk image, so it works while the VM is off.
+async fn snapshot_disk(vm_name: &str, label: &str) -> std::io::Result<()> {
+    let disk_path = get_data_dir().join("vms").join(vm_name).join("disk.img");
+    let snapshots_dir = disk_snapshots_dir(vm_name);
+    tokio::fs::create_dir_all(&snapshots_dir).await?;
+    tokio::fs::copy(disk_path, snapshots_dir.join(format!("{label}.img"))).await?;
+    Ok(())
+}
+
+async fn restore_snapshot(vm_name: &str, label: &str) -> std::io::Result<()> {
+    let disk_path = get_data_dir().join("vms").join(vm_name).join("disk.img");
+    let snapshot_path = disk_snapshots_dir(vm_name).join(format!("{label}.img"));
+    tokio::fs::copy(snapshot_path, disk_path).await?;
+    Ok(())
+}
+
+// Snapshot labels are just a creation timestamp, so "restore" always means
+// "roll back to the most recent disk backup" without prompting for a name.
+fn latest_disk_snapshot_label(vm_name: &str) -> Option<String> {
+    let entries = fs::read_dir(disk_snapshots_dir(vm_name)).ok()?;
+    entries
+        .flatten()
+        .filter_map(|entry| entry.path().file_stem()?.to_str().map(str::to_string))
+        .max()
+}
+
+fn unique_vm_name(base: &str) -> String {
+    let vms_dir = get_data_dir().join("vms");
+    let mut name = base.to_string();
+    let mut suffix = 1;
+    while vms_dir.join(&name).exists() {
+        name = format!("{}-{}", base, suffix);
+        suffix += 1;
+    }
+    name
+}
+
+// Registers a new bubble from an existing qcow2, converting it back to raw
+// and borrowing the kernel/initrd from the caller-chosen catalog image (the
+// disk alone doesn't say which distro it came from) like `create_vm` does.
+async fn import_vm(src: PathBuf, image_id: String) -> String {
+    let base = src.file_stem().and_then(OsStr::to_str).unwrap_or("imported-bubble");
+    let name = unique_vm_name(base);
+    let vm_dir_path = get_data_dir().join("vms").join(&name);
+    tokio::fs::create_dir_all(&vm_dir_path).await.expect("directories to be created");
+
+    let raw_path = vm_dir_path.join("disk.img");
+    gtk::gio::Subprocess::newv(&[
+        OsStr::new(qemu_img_bin()),
+        OsStr::new("convert"),
+        OsStr::new("-f"), OsStr::new("qcow2"),
+        OsStr::new("-O"), OsStr::new("raw"),
+        src.as_os_str(),
+        raw_path.as_os_str(),
+    ], SubprocessFlags::empty())
+        .expect("qemu-img to start")
+        .wait_future().await.expect("qemu-img to complete");
+
+    let catalog = load_image_catalog();
+    let chosen_image = catalog.iter().find(|entry| entry.id == image_id)
+        .unwrap_or_else(|| catalog.iter().find(|entry| entry.id == default_image_catalog()[0].id).unwrap_or(&catalog[0]));
+    let image_base_path = get_data_dir().join("images").join(&chosen_image.image_dir);
+    tokio::fs::copy(image_base_path.join("vmlinuz"), vm_dir_path.join("vmlinuz")).await.expect("vmlinuz copy to succeed");
+    tokio::fs::copy(image_base_path.join("initrd.img"), vm_dir_path.join("initrd.img")).await.expect("initrd copy to succeed");
+
+    let config = VmConfig { image_id: chosen_image.id.clone(), ..Default::default() };
+    save_vm_config(&name, &config).await.expect("config to be written");
+    name
+}
+
 #[derive(Debug)]
 enum VmMsg {
     PowerToggle(DynamicIndex),
     StartTerminal(DynamicIndex),
+    ShowSettings(DynamicIndex),
+    ShareFolder(DynamicIndex, PathBuf),
+    ShareSelected(DynamicIndex, u32),
+    UnshareFolder(DynamicIndex),
+    SuspendToggle(DynamicIndex),
+    Balloon(DynamicIndex),
+    SnapshotTake(DynamicIndex),
+    SnapshotRestore(DynamicIndex),
+    ExportDisk(DynamicIndex, PathBuf),
+    BackupDisk(DynamicIndex),
+    RestoreDiskBackup(DynamicIndex),
 }
 
 #[derive(Debug)]
 enum VmStateUpdate {
-    Update(DynamicIndex, VMStatus)
+    Update(DynamicIndex, VMStatus),
+    OpenSettings(DynamicIndex),
+    ExportStarted(String),
+    ExportFinished(String),
 }
 
 #[derive(PartialEq, Debug)]
 struct VmEntry {
     value: VM,
+    config: VmConfig,
+    selected_share_index: u32,
 }
 
 #[relm4::factory(async)]
@@ -364,6 +1281,7 @@ impl AsyncFactoryComponent for VmEntry {
                         VMStatus::NotRunning => "Stopped",
                         VMStatus::Running => "Running",
                         VMStatus::InFlux => "Working...",
+                        VMStatus::Suspended => "Suspended",
                     }
                 },
                 append = &gtk::Button {
@@ -380,6 +1298,145 @@ impl AsyncFactoryComponent for VmEntry {
                         sender.input(VmMsg::StartTerminal(index.clone()));
                     }
                 },
+                append = &gtk::Button {
+                    set_icon_name: "emblem-system-symbolic",
+                    set_tooltip_text: Some("Bubble settings"),
+                    connect_clicked[sender, index] => move |_| {
+                        sender.input(VmMsg::ShowSettings(index.clone()));
+                    }
+                },
+                append = &gtk::Button {
+                    set_icon_name: "folder-symbolic",
+                    // Shares are baked into the `--shared-dir` args built at the next
+                    // `PowerToggle` start, not hot-attached to a running crosvm, so
+                    // adding one only has an effect while the bubble is stopped.
+                    set_tooltip_text: Some("Share folder… (applies on next start)"),
+                    #[watch]
+                    set_sensitive: self.value.status == VMStatus::NotRunning,
+                    connect_clicked[sender, index] => move |_| {
+                        let sender = sender.clone();
+                        let index = index.clone();
+                        relm4::spawn_local(async move {
+                            let dialog = gtk::FileDialog::new();
+                            if let Ok(folder) = dialog.select_folder_future(None::<&gtk::Window>).await {
+                                if let Some(path) = folder.path() {
+                                    sender.input(VmMsg::ShareFolder(index, path));
+                                }
+                            }
+                        });
+                    }
+                },
+                append = &gtk::MenuButton {
+                    set_icon_name: "view-more-symbolic",
+                    set_tooltip_text: Some("More actions"),
+                    #[wrap(Some)]
+                    set_popover = &gtk::Popover {
+                        #[wrap(Some)]
+                        set_child = &gtk::Box {
+                            set_orientation: gtk::Orientation::Vertical,
+                            set_spacing: 4,
+                            set_margin_all: 8,
+                            append = &gtk::Button {
+                                #[watch]
+                                set_label: match self.value.status {
+                                    VMStatus::Suspended => "Resume",
+                                    _ => "Suspend",
+                                },
+                                #[watch]
+                                set_sensitive: matches!(self.value.status, VMStatus::Running | VMStatus::Suspended),
+                                connect_clicked[sender, index] => move |_| {
+                                    sender.input(VmMsg::SuspendToggle(index.clone()));
+                                }
+                            },
+                            append = &gtk::Button {
+                                set_label: "Reclaim memory",
+                                #[watch]
+                                set_sensitive: self.value.status == VMStatus::Running,
+                                connect_clicked[sender, index] => move |_| {
+                                    sender.input(VmMsg::Balloon(index.clone()));
+                                }
+                            },
+                            append = &gtk::Button {
+                                set_label: "Take snapshot",
+                                #[watch]
+                                set_sensitive: self.value.status == VMStatus::Running,
+                                connect_clicked[sender, index] => move |_| {
+                                    sender.input(VmMsg::SnapshotTake(index.clone()));
+                                }
+                            },
+                            append = &gtk::Button {
+                                set_label: "Restore snapshot",
+                                #[watch]
+                                set_sensitive: self.value.status == VMStatus::Running,
+                                connect_clicked[sender, index] => move |_| {
+                                    sender.input(VmMsg::SnapshotRestore(index.clone()));
+                                }
+                            },
+                            append = &gtk::Button {
+                                set_label: "Export…",
+                                #[watch]
+                                set_sensitive: self.value.status == VMStatus::NotRunning,
+                                connect_clicked[sender, index] => move |_| {
+                                    let sender = sender.clone();
+                                    let index = index.clone();
+                                    relm4::spawn_local(async move {
+                                        let dialog = gtk::FileDialog::new();
+                                        dialog.set_initial_name(Some("bubble.qcow2"));
+                                        if let Ok(file) = dialog.save_future(None::<&gtk::Window>).await {
+                                            if let Some(path) = file.path() {
+                                                sender.input(VmMsg::ExportDisk(index, path));
+                                            }
+                                        }
+                                    });
+                                }
+                            },
+                            append = &gtk::Button {
+                                set_label: "Backup disk",
+                                #[watch]
+                                set_sensitive: self.value.status == VMStatus::NotRunning,
+                                connect_clicked[sender, index] => move |_| {
+                                    sender.input(VmMsg::BackupDisk(index.clone()));
+                                }
+                            },
+                            append = &gtk::Button {
+                                set_label: "Restore disk backup",
+                                #[watch]
+                                set_sensitive: self.value.status == VMStatus::NotRunning,
+                                connect_clicked[sender, index] => move |_| {
+                                    sender.input(VmMsg::RestoreDiskBackup(index.clone()));
+                                }
+                            },
+                            append = &gtk::Separator {
+                                set_orientation: gtk::Orientation::Horizontal,
+                            },
+                            append = &gtk::Label {
+                                set_label: "Shared folders",
+                                set_halign: gtk::Align::Start,
+                            },
+                            append = &gtk::DropDown {
+                                #[watch]
+                                set_model: Some(&gtk::StringList::new(
+                                    &self.config.shared_folders.iter().map(|folder| folder.tag.as_str()).collect::<Vec<_>>()
+                                )),
+                                #[watch]
+                                set_sensitive: !self.config.shared_folders.is_empty(),
+                                connect_selected_notify[sender, index] => move |dropdown| {
+                                    sender.input(VmMsg::ShareSelected(index.clone(), dropdown.selected()));
+                                },
+                            },
+                            append = &gtk::Button {
+                                // Same as the share button above: only takes effect
+                                // on the bubble's next start, so only enable it then.
+                                set_label: "Remove selected share (applies on next start)",
+                                #[watch]
+                                set_sensitive: self.value.status == VMStatus::NotRunning && !self.config.shared_folders.is_empty(),
+                                connect_clicked[sender, index] => move |_| {
+                                    sender.input(VmMsg::UnshareFolder(index.clone()));
+                                }
+                            },
+                        }
+                    }
+                },
             }
         }
     }
@@ -389,7 +1446,8 @@ impl AsyncFactoryComponent for VmEntry {
         _index: &DynamicIndex,
         _sender: AsyncFactorySender<Self>,
     ) -> Self {
-        Self { value }
+        let config = load_vm_config(&value.name);
+        Self { value, config, selected_share_index: 0 }
     }
     async fn update(&mut self, msg: Self::Input, sender: AsyncFactorySender<Self>) {
         let vm_name: String = self.value.name.clone();
@@ -403,8 +1461,19 @@ impl AsyncFactoryComponent for VmEntry {
                             request_shutdown(&vsock_socket_path).await;
                         });
                     },
+                    VMStatus::Suspended => {
+                        // crosvm suspend freezes the guest's vCPUs, so the guest-side
+                        // HTTP handler can't see a shutdown request until it's resumed.
+                        let crosvm_socket_path = image_base_path.join("crosvm_socket");
+                        sender.output(VmStateUpdate::Update(index.clone(), VMStatus::Running)).unwrap();
+                        relm4::spawn_local(async move {
+                            crosvm_resume(&crosvm_socket_path).await;
+                            request_shutdown(&vsock_socket_path).await;
+                        });
+                    },
                     VMStatus::NotRunning => {
                         sender.output(VmStateUpdate::Update(index.clone(), VMStatus::InFlux)).unwrap();
+                        let config = load_vm_config(&vm_name);
                         relm4::spawn_local(async move {
                             let crosvm_socket_path = image_base_path.join("crosvm_socket");
                             let passt_socket_path = Path::new("/tmp").join(format!("passt_socket_{}", vm_name.clone()));
@@ -412,6 +1481,15 @@ impl AsyncFactoryComponent for VmEntry {
                             let image_linuz_path = image_base_path.join("vmlinuz");
                             let image_initrd_path = image_base_path.join("initrd.img");
 
+                            let base_image_disk_path = load_image_catalog().into_iter()
+                                .find(|entry| entry.id == config.image_id)
+                                .map(|entry| get_data_dir().join("images").join(entry.image_dir).join("disk.img"));
+                            if let Some(base_image_disk_path) = base_image_disk_path {
+                                if let Ok(base_meta) = tokio::fs::metadata(&base_image_disk_path).await {
+                                    ensure_disk_capacity(&image_disk_path, base_meta.len(), config.extra_disk_gib).await;
+                                }
+                            }
+
                             let socat_bin: OsString = if is_flatpak() {
                                 flatpak_host_bin("socat").into_os_string()
                             } else {
@@ -453,15 +1531,24 @@ impl AsyncFactoryComponent for VmEntry {
                             let wayland_sock = wayland_sock_path();
                             let vsock_cid = format!("{}", index.current_index() + 10);
                             let passt_socket_str = format!("net,socket={}", passt_socket_path.to_str().expect("string"));
-                            let crosvm_host_args = make_host_args(&[
+                            let cpus_arg = format!("num-cores={}", config.cpu_cores);
+                            let mem_arg = format!("{}", config.memory_mib);
+                            let shared_dir_args: Vec<String> = config.shared_folders.iter()
+                                .map(|folder| format!(
+                                    "{}:{}:type=fs",
+                                    folder.host_path.to_str().expect("string"),
+                                    folder.tag,
+                                ))
+                                .collect();
+                            let mut crosvm_args: Vec<&OsStr> = vec![
                                 crosvm_bin.as_os_str(),
                                 OsStr::new("run"),
                                 OsStr::new("--name"),
                                 OsStr::new(&vm_name),
                                 OsStr::new("--cpus"),
-                                OsStr::new("num-cores=4"),
+                                OsStr::new(&cpus_arg),
                                 OsStr::new("-m"),
-                                OsStr::new("7000"),
+                                OsStr::new(&mem_arg),
                                 OsStr::new("--rwdisk"),
                                 image_disk_path.as_os_str(),
                                 OsStr::new("--initrd"),
@@ -476,10 +1563,20 @@ impl AsyncFactoryComponent for VmEntry {
                                 wayland_sock.as_os_str(),
                                 OsStr::new("--vhost-user"),
                                 OsStr::new(&passt_socket_str),
-                                OsStr::new("-p"),
-                                OsStr::new("root=/dev/vda2"),
-                                image_linuz_path.as_os_str(),
-                            ]);
+                            ];
+                            for shared_dir in &shared_dir_args {
+                                crosvm_args.push(OsStr::new("--shared-dir"));
+                                crosvm_args.push(OsStr::new(shared_dir));
+                            }
+                            let audio_sock = audio_sock_path();
+                            if config.audio_enabled {
+                                crosvm_args.push(OsStr::new("--sound"));
+                                crosvm_args.push(audio_sock.as_os_str());
+                            }
+                            crosvm_args.push(OsStr::new("-p"));
+                            crosvm_args.push(OsStr::new("root=/dev/vda2"));
+                            crosvm_args.push(image_linuz_path.as_os_str());
+                            let crosvm_host_args = make_host_args(&crosvm_args);
                             let crosvm_host_args_ref: Vec<&OsStr> = crosvm_host_args.iter().map(OsString::as_os_str).collect();
                             let crosvm_process = gtk::gio::Subprocess::newv(
                                 &crosvm_host_args_ref,
@@ -503,20 +1600,144 @@ impl AsyncFactoryComponent for VmEntry {
                     request_terminal(&vsock_socket_path).await;
                 });
             }
+            VmMsg::ShowSettings(index) => {
+                sender.output(VmStateUpdate::OpenSettings(index)).unwrap();
+            }
+            VmMsg::ShareFolder(_index, host_path) => {
+                if self.value.status != VMStatus::NotRunning {
+                    return;
+                }
+                let host_path = resolve_host_path(&host_path).await;
+                let tag = sanitize_tag(&host_path);
+                self.config.shared_folders.push(SharedFolder { host_path, tag });
+                let vm_name = vm_name.clone();
+                let config = self.config.clone();
+                relm4::spawn_local(async move {
+                    save_vm_config(&vm_name, &config).await.ok();
+                });
+            }
+            VmMsg::ShareSelected(_index, selected) => {
+                self.selected_share_index = selected;
+            }
+            VmMsg::UnshareFolder(_index) => {
+                if self.value.status != VMStatus::NotRunning {
+                    return;
+                }
+                let selected = self.selected_share_index as usize;
+                if selected < self.config.shared_folders.len() {
+                    self.config.shared_folders.remove(selected);
+                    self.selected_share_index = 0;
+                    let vm_name = vm_name.clone();
+                    let config = self.config.clone();
+                    relm4::spawn_local(async move {
+                        save_vm_config(&vm_name, &config).await.ok();
+                    });
+                }
+            }
+            VmMsg::SuspendToggle(index) => {
+                let crosvm_socket_path = image_base_path.join("crosvm_socket");
+                match self.value.status {
+                    VMStatus::Running => {
+                        sender.output(VmStateUpdate::Update(index, VMStatus::Suspended)).unwrap();
+                        relm4::spawn_local(async move {
+                            crosvm_suspend(&crosvm_socket_path).await;
+                        });
+                    }
+                    VMStatus::Suspended => {
+                        sender.output(VmStateUpdate::Update(index, VMStatus::Running)).unwrap();
+                        relm4::spawn_local(async move {
+                            crosvm_resume(&crosvm_socket_path).await;
+                        });
+                    }
+                    _ => {}
+                }
+            }
+            VmMsg::Balloon(_index) => {
+                let crosvm_socket_path = image_base_path.join("crosvm_socket");
+                let target_mib = self.config.memory_mib / 2;
+                relm4::spawn_local(async move {
+                    crosvm_balloon(&crosvm_socket_path, target_mib).await;
+                });
+            }
+            VmMsg::SnapshotTake(_index) => {
+                let crosvm_socket_path = image_base_path.join("crosvm_socket");
+                let snapshot_path = image_base_path.join("snapshot.img");
+                relm4::spawn_local(async move {
+                    crosvm_snapshot_take(&crosvm_socket_path, &snapshot_path).await;
+                });
+            }
+            VmMsg::SnapshotRestore(_index) => {
+                let crosvm_socket_path = image_base_path.join("crosvm_socket");
+                let snapshot_path = image_base_path.join("snapshot.img");
+                relm4::spawn_local(async move {
+                    crosvm_snapshot_restore(&crosvm_socket_path, &snapshot_path).await;
+                });
+            }
+            VmMsg::ExportDisk(index, dest) => {
+                if self.value.status != VMStatus::NotRunning {
+                    return;
+                }
+                sender.output(VmStateUpdate::Update(index.clone(), VMStatus::InFlux)).unwrap();
+                sender.output(VmStateUpdate::ExportStarted(vm_name.clone())).unwrap();
+                relm4::spawn_local(async move {
+                    export_disk(&vm_name, &dest).await;
+                    sender.output(VmStateUpdate::Update(index, VMStatus::NotRunning)).unwrap();
+                    sender.output(VmStateUpdate::ExportFinished(vm_name)).unwrap();
+                });
+            }
+            VmMsg::BackupDisk(index) => {
+                if self.value.status != VMStatus::NotRunning {
+                    return;
+                }
+                let label = std::time::SystemTime::now()
+                    .duration_since(std::time::UNIX_EPOCH)
+                    .expect("clock before epoch")
+                    .as_secs()
+                    .to_string();
+                sender.output(VmStateUpdate::Update(index.clone(), VMStatus::InFlux)).unwrap();
+                relm4::spawn_local(async move {
+                    snapshot_disk(&vm_name, &label).await.ok();
+                    sender.output(VmStateUpdate::Update(index, VMStatus::NotRunning)).unwrap();
+                });
+            }
+            VmMsg::RestoreDiskBackup(index) => {
+                if self.value.status != VMStatus::NotRunning {
+                    return;
+                }
+                let Some(label) = latest_disk_snapshot_label(&vm_name) else {
+                    return;
+                };
+                sender.output(VmStateUpdate::Update(index.clone(), VMStatus::InFlux)).unwrap();
+                relm4::spawn_local(async move {
+                    restore_snapshot(&vm_name, &label).await.ok();
+                    sender.output(VmStateUpdate::Update(index, VMStatus::NotRunning)).unwrap();
+                });
+            }
         }
     }
 }
 
 #[derive(Debug)]
 enum AppMsg {
-    DownloadImage,
-    FinishImageDownload,
+    HandleImageStatusUpdate(DynamicIndex, ImageStatus),
     ShowBubbleCreationDialog,
     HideBubbleCreationDialog,
-    CreateNewBubble(String),
+    CreateNewBubble { name: String, image_id: String },
+    DownloadCatalogImage(String),
+    CatalogImageProgress(String, f64),
+    CatalogImageFinished(String, Result<(), String>),
+    ShowImportBubbleDialog,
+    HideImportBubbleDialog,
+    ImportBubble(PathBuf, String),
     HandleVMStatusUpdate(DynamicIndex, VMStatus),
     FinishBubbleCreation,
+    JobFinished(JobId),
+    VmExportStarted(String),
+    VmExportFinished(String),
+    ShowVmSettings(DynamicIndex),
     CloseApplication,
+    RemoteUpdateFound(String, ImageStatus),
+    UpdateBannerClicked,
 }
 
 #[relm4::component]
@@ -541,47 +1762,29 @@ impl SimpleComponent for App {
                     pack_end = &gtk::Button{
                         set_icon_name: "list-add-symbolic",
                         #[watch]
-                        set_sensitive: !model.currently_creating_bubble && model.image_status == ImageStatus::Present,
+                        set_sensitive: model.any_image_present,
                         set_tooltip_text: Some("Create new bubble"),
                         connect_clicked => AppMsg::ShowBubbleCreationDialog,
                     },
                     pack_end = &gtk::Spinner{
                         #[watch]
-                        set_spinning: model.currently_creating_bubble
+                        set_spinning: !model.jobs.is_empty()
                     },
                 },
+                add_top_bar = &relm4::adw::Banner {
+                    #[watch]
+                    set_revealed: model.update_banner.is_some(),
+                    #[watch]
+                    set_title: &model.update_banner.as_ref()
+                        .map(|(_, display_name)| format!("An update is available for {display_name}"))
+                        .unwrap_or_default(),
+                    set_button_label: Some("Update"),
+                    connect_button_clicked => AppMsg::UpdateBannerClicked,
+                },
                 #[wrap(Some)]
                 set_content: stack = &relm4::adw::ViewStack {
-                    add = &gtk::ListBox {
-                        append = &relm4::adw::ActionRow {
-                            set_title: "Debian 13 Bubbles Distribution",
-                            add_prefix = &gtk::Image {
-                                set_icon_name: Some("drive-harddisk-system-symbolic")
-                            },
-                            add_suffix = &gtk::Box {
-                                set_orientation: gtk::Orientation::Horizontal,
-                                set_spacing: 5,
-                                append = &gtk::Label {
-                                    #[watch]
-                                    set_label: match model.image_status {
-                                        ImageStatus::Present => "Ready",
-                                        ImageStatus::NotPresent => "Not downloaded",
-                                        ImageStatus::Downloading => "Downloading...",
-                                    }
-                                },
-                                append = &gtk::Button {
-                                    #[watch]
-                                    set_sensitive: model.image_status != ImageStatus::Downloading,
-                                    #[watch]
-                                    set_icon_name: match model.image_status {
-                                        ImageStatus::Present => "view-refresh-symbolic",
-                                        ImageStatus::NotPresent => "folder-download-symbolic",
-                                        ImageStatus::Downloading => "image-loading-symbolic",
-                                    },
-                                    connect_clicked => AppMsg::DownloadImage,
-                                }
-                            }
-                        }
+                    #[local_ref]
+                    add = images_list -> gtk::ListBox {
                     } -> {
                         set_title: Some("Images"),
                         set_icon_name: Some("drive-harddisk-system-symbolic")
@@ -593,12 +1796,22 @@ impl SimpleComponent for App {
                             set_description: Some("Make sure to download an image, then click below."),
                             set_icon_name: Some("computer"),
                             #[wrap(Some)]
-                            set_child = &gtk::Button {
-                                #[watch]
-                                set_sensitive: !model.currently_creating_bubble && model.image_status == ImageStatus::Present,
-                                set_css_classes: &["pill", "suggested-action"],
-                                set_label: "Create new Bubble",
-                                connect_clicked => AppMsg::ShowBubbleCreationDialog
+                            set_child = &gtk::Box {
+                                set_orientation: gtk::Orientation::Horizontal,
+                                set_spacing: 8,
+                                set_halign: gtk::Align::Center,
+                                append = &gtk::Button {
+                                    #[watch]
+                                    set_sensitive: model.any_image_present,
+                                    set_css_classes: &["pill", "suggested-action"],
+                                    set_label: "Create new Bubble",
+                                    connect_clicked => AppMsg::ShowBubbleCreationDialog
+                                },
+                                append = &gtk::Button {
+                                    set_css_classes: &["pill"],
+                                    set_label: "Import…",
+                                    connect_clicked => AppMsg::ShowImportBubbleDialog
+                                },
                             }
                         },
                         #[watch]
@@ -630,9 +1843,23 @@ impl SimpleComponent for App {
                 .launch_default()
                 .forward(sender.input_sender(), |output| match output {
                     VmStateUpdate::Update(index, status_update  ) => AppMsg::HandleVMStatusUpdate(index, status_update),
+                    VmStateUpdate::OpenSettings(index) => AppMsg::ShowVmSettings(index),
+                    VmStateUpdate::ExportStarted(vm) => AppMsg::VmExportStarted(vm),
+                    VmStateUpdate::ExportFinished(vm) => AppMsg::VmExportFinished(vm),
+                });
+        let images: AsyncFactoryVecDeque<ImageEntry> =
+            AsyncFactoryVecDeque::builder()
+                .launch_default()
+                .forward(sender.input_sender(), |output| match output {
+                    ImageEntryUpdate::StatusChanged(index, status) => AppMsg::HandleImageStatusUpdate(index, status),
                 });
         let create_bubble_dialog = CreateBubbleDialog::builder()
-            .launch(())
+            .launch(load_image_catalog())
+            .forward(sender.input_sender(), |msg| match msg {
+                msg => msg
+            });
+        let import_bubble_dialog = ImportBubbleDialog::builder()
+            .launch(load_image_catalog())
             .forward(sender.input_sender(), |msg| match msg {
                 msg => msg
             });
@@ -641,20 +1868,38 @@ impl SimpleComponent for App {
             .forward(sender.input_sender(), |msg| match msg {
                 msg => msg
             });
+        let vm_settings_dialog = VmSettingsDialog::builder()
+            .launch(())
+            .forward(sender.input_sender(), |_msg: ()| unreachable!());
 
         let mut model = App {
             vms,
+            images,
             create_bubble_dialog,
+            import_bubble_dialog,
             warn_close_dialog,
+            vm_settings_dialog,
             root: root.clone(),
-            currently_creating_bubble: false,
-            image_status: determine_download_status(),
+            jobs: HashMap::new(),
+            next_job_id: 0,
+            any_image_present: false,
+            any_image_downloading: false,
+            update_banner: None,
         };
         for vm in load_vms() {
             model.vms.guard().push_back(vm);
         }
+        for entry in load_image_catalog() {
+            model.images.guard().push_back(entry);
+        }
+        model.any_image_present = model.images.guard().iter().any(|image| matches!(image.status, ImageStatus::Present | ImageStatus::UpdateAvailable { .. }));
+        model.any_image_downloading = model.images.guard().iter().any(|image| matches!(image.status, ImageStatus::Downloading { .. }));
+        for entry in load_image_catalog() {
+            spawn_remote_update_check(&sender, entry);
+        }
         let vms_stack = &gtk::Stack::new();
         vms_stack.add_named(model.vms.widget(), Some("vm-view"));
+        let images_list = model.images.widget();
 
         let widgets = view_output!();
 
@@ -669,34 +1914,121 @@ impl SimpleComponent for App {
             AppMsg::HideBubbleCreationDialog=>{
                 self.create_bubble_dialog.widgets().dialog.close();
             }
-            AppMsg::CreateNewBubble(name) => {
-                self.currently_creating_bubble = true;
+            AppMsg::ShowImportBubbleDialog=>{
+                self.import_bubble_dialog.widgets().dialog.present(Some(&self.root));
+            }
+            AppMsg::HideImportBubbleDialog=>{
+                self.import_bubble_dialog.widgets().dialog.close();
+            }
+            AppMsg::CreateNewBubble { name, image_id } => {
+                if self.is_job_running(&JobKind::CreateBubble { name: name.clone() }) {
+                    return;
+                }
+                let job_id = self.start_job(JobKind::CreateBubble { name: name.clone() });
                 spawn(async move {
-                    create_vm(name).await;
+                    create_vm(name, image_id).await;
+                    sender.input(AppMsg::JobFinished(job_id));
+                    sender.input(AppMsg::FinishBubbleCreation);
+                });
+            }
+            AppMsg::DownloadCatalogImage(image_id) => {
+                if self.is_job_running(&JobKind::DownloadImage { image_id: image_id.clone() }) {
+                    return;
+                }
+                let Some(position) = self.images.guard().iter().position(|image| image.entry.id == image_id) else {
+                    return;
+                };
+                let entry = self.images.guard().get(position).unwrap().entry.clone();
+                self.images.guard().get_mut(position).unwrap().status = ImageStatus::Downloading { fraction: 0.0 };
+                self.any_image_downloading = true;
+                self.create_bubble_dialog.emit(CreateBubbleDialogMsg::ImageStatusChanged(image_id.clone(), ImageStatus::Downloading { fraction: 0.0 }));
+                let job_id = self.start_job(JobKind::DownloadImage { image_id: image_id.clone() });
+
+                let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+                let progress_sender = sender.clone();
+                let progress_id = image_id.clone();
+                relm4::spawn_local(async move {
+                    while let Some((downloaded, total)) = rx.recv().await {
+                        let fraction = if total == 0 { 0.0 } else { (downloaded as f64 / total as f64).min(0.99) };
+                        progress_sender.input(AppMsg::CatalogImageProgress(progress_id.clone(), fraction));
+                    }
+                });
+                spawn(async move {
+                    let result = download_image(&entry, tx).await;
+                    sender.input(AppMsg::JobFinished(job_id));
+                    sender.input(AppMsg::CatalogImageFinished(image_id, result));
+                });
+            }
+            AppMsg::CatalogImageProgress(image_id, fraction) => {
+                if let Some(position) = self.images.guard().iter().position(|image| image.entry.id == image_id) {
+                    self.images.guard().get_mut(position).unwrap().status = ImageStatus::Downloading { fraction };
+                }
+                self.create_bubble_dialog.emit(CreateBubbleDialogMsg::ImageStatusChanged(image_id, ImageStatus::Downloading { fraction }));
+            }
+            AppMsg::CatalogImageFinished(image_id, result) => {
+                let Some(position) = self.images.guard().iter().position(|image| image.entry.id == image_id) else {
+                    return;
+                };
+                let entry = self.images.guard().get(position).unwrap().entry.clone();
+                let status = match result {
+                    Ok(()) => determine_download_status(&entry),
+                    Err(reason) => ImageStatus::Failed(reason),
+                };
+                self.images.guard().get_mut(position).unwrap().status = status.clone();
+                self.any_image_present = self.images.guard().iter().any(|image| matches!(image.status, ImageStatus::Present | ImageStatus::UpdateAvailable { .. }));
+                self.any_image_downloading = self.images.guard().iter().any(|image| matches!(image.status, ImageStatus::Downloading { .. }));
+                if status == ImageStatus::Present {
+                    spawn_remote_update_check(&sender, entry);
+                }
+                self.create_bubble_dialog.emit(CreateBubbleDialogMsg::ImageStatusChanged(image_id, status));
+            }
+            AppMsg::ImportBubble(src, image_id) => {
+                let name = src.to_string_lossy().into_owned();
+                if self.is_job_running(&JobKind::CreateBubble { name: name.clone() }) {
+                    return;
+                }
+                let job_id = self.start_job(JobKind::CreateBubble { name });
+                spawn(async move {
+                    import_vm(src, image_id).await;
+                    sender.input(AppMsg::JobFinished(job_id));
                     sender.input(AppMsg::FinishBubbleCreation);
                 });
             }
             AppMsg::FinishBubbleCreation=>{
                 let new_vms = load_vms();
-                self.currently_creating_bubble = false;
                 self.vms.guard().clear();
                 for vm in new_vms {
                     self.vms.guard().push_back(vm);
                 }
             }
-            AppMsg::DownloadImage => {
-                self.image_status = ImageStatus::Downloading;
-                relm4::spawn_local(async move {
-                    download_image().await;
-                    sender.input(AppMsg::FinishImageDownload);
-                });
+            AppMsg::JobFinished(job_id) => {
+                self.jobs.remove(&job_id);
+            }
+            AppMsg::VmExportStarted(vm) => {
+                self.start_job(JobKind::ExportDisk { vm });
             }
-            AppMsg::FinishImageDownload => {
-                self.image_status = determine_download_status();
+            AppMsg::VmExportFinished(vm) => {
+                self.jobs.retain(|_, kind| *kind != JobKind::ExportDisk { vm: vm.clone() });
+            }
+            AppMsg::HandleImageStatusUpdate(index, status) => {
+                let entry = self.images.guard().get(index.current_index()).unwrap().entry.clone();
+                self.images.guard().get_mut(index.current_index()).unwrap().status = status.clone();
+                self.any_image_present = self.images.guard().iter().any(|image| matches!(image.status, ImageStatus::Present | ImageStatus::UpdateAvailable { .. }));
+                self.any_image_downloading = self.images.guard().iter().any(|image| matches!(image.status, ImageStatus::Downloading { .. }));
+                if status == ImageStatus::Present {
+                    spawn_remote_update_check(&sender, entry.clone());
+                }
+                self.create_bubble_dialog.emit(CreateBubbleDialogMsg::ImageStatusChanged(entry.id, status));
             }
             AppMsg::HandleVMStatusUpdate(index, status_update) => {
                 self.vms.guard().get_mut(index.current_index()).unwrap().value.status = status_update;
             }
+            AppMsg::ShowVmSettings(index) => {
+                let vm_name = self.vms.guard().get(index.current_index()).unwrap().value.name.clone();
+                let config = load_vm_config(&vm_name);
+                self.vm_settings_dialog.emit(VmSettingsDialogMsg::SetTarget(vm_name, config));
+                self.vm_settings_dialog.widgets().dialog.present(Some(&self.root));
+            }
             AppMsg::CloseApplication => {
                 let mut vm_running = false;
                 for vm in self.vms.guard().iter_mut() {
@@ -704,13 +2036,27 @@ impl SimpleComponent for App {
                         vm_running = true;
                     }
                 }
-                if self.image_status == ImageStatus::Downloading || self.currently_creating_bubble || vm_running {
+                if self.any_image_downloading || !self.jobs.is_empty() || vm_running {
                     self.warn_close_dialog.widgets().dialog.present(Some(&self.root));
                     return
                 }
 
                 relm4::main_application().quit();
             }
+            AppMsg::RemoteUpdateFound(image_id, status) => {
+                if let Some(position) = self.images.guard().iter().position(|image| image.entry.id == image_id) {
+                    let display_name = self.images.guard().get(position).unwrap().entry.display_name.clone();
+                    self.images.guard().get_mut(position).unwrap().status = status.clone();
+                    self.any_image_present = self.images.guard().iter().any(|image| matches!(image.status, ImageStatus::Present | ImageStatus::UpdateAvailable { .. }));
+                    self.update_banner = Some((image_id.clone(), display_name));
+                    self.create_bubble_dialog.emit(CreateBubbleDialogMsg::ImageStatusChanged(image_id, status));
+                }
+            }
+            AppMsg::UpdateBannerClicked => {
+                if let Some((image_id, _)) = self.update_banner.take() {
+                    sender.input(AppMsg::DownloadCatalogImage(image_id));
+                }
+            }
         }
     }
 }